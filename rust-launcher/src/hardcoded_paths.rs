@@ -20,12 +20,259 @@ const RHINO_JAR: Option<&'static str> = option_env!("RHINO_JAR");
 const ITW_LIBS: Option<&'static str> = option_env!("ITW_LIBS");
 
 
-pub fn get_jre() -> &'static str {
-    JRE.unwrap_or("JRE-dev-unspecified")
+pub mod discovery {
+    use std::env;
+    use std::path::{Path, PathBuf};
+
+    /// Enumerates likely JRE installation roots, most-preferred first.
+    pub trait JreCandidateSource {
+        fn candidate_roots(&self) -> Vec<PathBuf>;
+    }
+
+    #[cfg(target_os = "macos")]
+    const STANDARD_INSTALL_ROOTS: [&str; 1] = ["/Library/Java/JavaVirtualMachines"];
+    #[cfg(all(unix, not(target_os = "macos")))]
+    const STANDARD_INSTALL_ROOTS: [&str; 2] = ["/usr/lib/jvm", "/usr/java"];
+    #[cfg(windows)]
+    const STANDARD_INSTALL_ROOTS: [&str; 1] = ["C:\\Program Files\\Java"];
+
+    /// Expands a standard install root into one or more JRE home candidates. On macOS,
+    /// `STANDARD_INSTALL_ROOTS` names a directory of `*.jdk` bundles, each containing its real
+    /// home at `Contents/Home`; everywhere else the root itself is the candidate.
+    fn expand_standard_root(root: &Path) -> Vec<PathBuf> {
+        #[cfg(target_os = "macos")]
+        {
+            std::fs::read_dir(root)
+                .map(|entries| {
+                    entries
+                        .flatten()
+                        .map(|entry| entry.path())
+                        .filter(|path| path.extension().map_or(false, |ext| ext == "jdk"))
+                        .map(|path| path.join("Contents").join("Home"))
+                        .collect()
+                })
+                .unwrap_or_else(|_| Vec::new())
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            vec![root.to_path_buf()]
+        }
+    }
+
+    impl JreCandidateSource for os_access::Os {
+        fn candidate_roots(&self) -> Vec<PathBuf> {
+            let mut roots = Vec::new();
+            if let Ok(java_home) = env::var("JAVA_HOME") {
+                roots.push(PathBuf::from(java_home));
+            }
+            if let Ok(path_var) = env::var("PATH") {
+                for entry in env::split_paths(&path_var) {
+                    roots.push(entry);
+                }
+            }
+            for standard_root in STANDARD_INSTALL_ROOTS.iter() {
+                roots.extend(expand_standard_root(Path::new(standard_root)));
+            }
+            roots
+        }
+    }
+
+    /// A `Home` root has `bin/java` underneath it; a `BinDir` (e.g. `/usr/bin` via a `PATH`
+    /// entry) is itself a `bin` directory and has `java` directly inside it — kept distinct so
+    /// callers build the right `java` path for each shape instead of assuming `root/bin/java`.
+    pub enum JreCandidate {
+        Home(PathBuf),
+        BinDir(PathBuf),
+    }
+
+    impl JreCandidate {
+        pub fn jre_root(&self) -> PathBuf {
+            match self {
+                JreCandidate::Home(root) => root.clone(),
+                JreCandidate::BinDir(bin_dir) => bin_dir.parent().map(Path::to_path_buf).unwrap_or_else(|| bin_dir.clone()),
+            }
+        }
+
+        pub fn java_path(&self) -> PathBuf {
+            match self {
+                JreCandidate::Home(root) => {
+                    if root.join("bin").join("java.exe").exists() {
+                        root.join("bin").join("java.exe")
+                    } else {
+                        root.join("bin").join("java")
+                    }
+                }
+                JreCandidate::BinDir(bin_dir) => {
+                    if bin_dir.join("java.exe").exists() {
+                        bin_dir.join("java.exe")
+                    } else {
+                        bin_dir.join("java")
+                    }
+                }
+            }
+        }
+    }
+
+    /// A regular, executable file — not a directory, and (on unix) not missing its execute
+    /// bit. A `PATH` entry is unioned in wholesale, so a stray non-executable `java` file or a
+    /// subdirectory literally named `java` must not be mistaken for a usable candidate.
+    #[cfg(unix)]
+    fn is_executable_file(path: &Path) -> bool {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::metadata(path)
+            .map(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+            .unwrap_or(false)
+    }
+
+    #[cfg(not(unix))]
+    fn is_executable_file(path: &Path) -> bool {
+        path.is_file()
+    }
+
+    fn classify(root: &Path) -> Option<JreCandidate> {
+        if is_executable_file(&root.join("bin").join("java")) || is_executable_file(&root.join("bin").join("java.exe")) {
+            Some(JreCandidate::Home(root.to_path_buf()))
+        } else if is_executable_file(&root.join("java")) || is_executable_file(&root.join("java.exe")) {
+            Some(JreCandidate::BinDir(root.to_path_buf()))
+        } else {
+            None
+        }
+    }
+
+    pub fn discover_jre<S: JreCandidateSource>(source: &S) -> Option<JreCandidate> {
+        source.candidate_roots().into_iter().find_map(|root| classify(&root))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::fs;
+
+        struct MockCandidateSource {
+            roots: Vec<PathBuf>,
+        }
+
+        impl JreCandidateSource for MockCandidateSource {
+            fn candidate_roots(&self) -> Vec<PathBuf> {
+                self.roots.clone()
+            }
+        }
+
+        #[cfg(unix)]
+        fn make_executable(path: &Path) {
+            use std::os::unix::fs::PermissionsExt;
+            let mut permissions = fs::metadata(path).unwrap().permissions();
+            permissions.set_mode(0o755);
+            fs::set_permissions(path, permissions).unwrap();
+        }
+
+        #[cfg(not(unix))]
+        fn make_executable(_path: &Path) {}
+
+        fn make_fake_jre_root(name: &str) -> PathBuf {
+            let root = env::temp_dir().join(format!("itw-hardcoded-paths-discovery-test-{}", name));
+            fs::create_dir_all(root.join("bin")).unwrap();
+            let java = root.join("bin").join("java");
+            fs::write(&java, "").unwrap();
+            make_executable(&java);
+            root
+        }
+
+        fn make_fake_bin_dir(name: &str) -> PathBuf {
+            let bin_dir = env::temp_dir().join(format!("itw-hardcoded-paths-discovery-test-{}", name));
+            fs::create_dir_all(&bin_dir).unwrap();
+            let java = bin_dir.join("java");
+            fs::write(&java, "").unwrap();
+            make_executable(&java);
+            bin_dir
+        }
+
+        #[test]
+        fn discovers_first_usable_candidate_in_order() {
+            let usable_root = make_fake_jre_root("first-usable");
+            let unusable_root = env::temp_dir().join("itw-hardcoded-paths-discovery-test-nonexistent");
+            let source = MockCandidateSource { roots: vec![unusable_root, usable_root.clone()] };
+
+            let discovered = discover_jre(&source);
+            assert_eq!(discovered.map(|c| c.jre_root()), Some(usable_root));
+        }
+
+        #[test]
+        fn returns_none_when_no_candidate_is_usable() {
+            let source = MockCandidateSource {
+                roots: vec![env::temp_dir().join("itw-hardcoded-paths-discovery-test-nonexistent")],
+            };
+
+            assert!(discover_jre(&source).is_none());
+        }
+
+        #[cfg(unix)]
+        #[test]
+        fn non_executable_java_file_is_not_a_usable_candidate() {
+            let root = env::temp_dir().join("itw-hardcoded-paths-discovery-test-non-executable");
+            fs::create_dir_all(root.join("bin")).unwrap();
+            let java = root.join("bin").join("java");
+            fs::write(&java, "").unwrap();
+            use std::os::unix::fs::PermissionsExt;
+            let mut permissions = fs::metadata(&java).unwrap().permissions();
+            permissions.set_mode(0o644);
+            fs::set_permissions(&java, permissions).unwrap();
+
+            let source = MockCandidateSource { roots: vec![root] };
+            assert!(discover_jre(&source).is_none());
+        }
+
+        #[cfg(unix)]
+        #[test]
+        fn directory_named_java_is_not_a_usable_candidate() {
+            let root = env::temp_dir().join("itw-hardcoded-paths-discovery-test-java-is-a-dir");
+            fs::create_dir_all(root.join("java")).unwrap();
+
+            let source = MockCandidateSource { roots: vec![root] };
+            assert!(discover_jre(&source).is_none());
+        }
+
+        #[test]
+        fn bin_dir_candidate_reports_parent_as_jre_root_and_builds_existing_java_path() {
+            let bin_dir = make_fake_bin_dir("path-entry-bin-dir");
+            let source = MockCandidateSource { roots: vec![bin_dir.clone()] };
+
+            let candidate = discover_jre(&source).expect("bin dir should be discovered");
+            assert_eq!(candidate.jre_root(), bin_dir.parent().unwrap().to_path_buf());
+            assert!(candidate.java_path().exists());
+            assert_eq!(candidate.java_path(), bin_dir.join("java"));
+        }
+
+        #[cfg(target_os = "macos")]
+        #[test]
+        fn expand_standard_root_probes_jdk_bundle_contents_home() {
+            let install_root = env::temp_dir().join("itw-hardcoded-paths-discovery-test-jvm-install-root");
+            fs::create_dir_all(install_root.join("Temurin-21.jdk").join("Contents").join("Home").join("bin")).unwrap();
+            fs::write(install_root.join("Temurin-21.jdk").join("Contents").join("Home").join("bin").join("java"), "").unwrap();
+            fs::create_dir_all(install_root.join("not-a-jdk-bundle")).unwrap();
+
+            let homes = expand_standard_root(&install_root);
+            assert_eq!(homes, vec![install_root.join("Temurin-21.jdk").join("Contents").join("Home")]);
+        }
+    }
 }
 
-pub fn get_java() -> &'static str {
-    JAVA.unwrap_or("JAVA-dev-unspecified")
+pub fn get_jre(logger: &os_access::Os) -> String {
+    match JRE {
+        Some(value) => String::from(value),
+        None => discovery::discover_jre(logger)
+            .map(|candidate| candidate.jre_root().to_string_lossy().into_owned())
+            .unwrap_or_else(|| String::from("JRE-dev-unspecified")),
+    }
+}
+
+pub fn get_java(logger: &os_access::Os) -> String {
+    match JAVA {
+        Some(value) => String::from(value),
+        None => discovery::discover_jre(logger)
+            .map(|candidate| candidate.java_path().to_string_lossy().into_owned())
+            .unwrap_or_else(|| String::from("JAVA-dev-unspecified")),
+    }
 }
 
 pub fn get_main() -> &'static str {
@@ -92,45 +339,385 @@ impl FromStr for ItwLibSearch {
     }
 }
 
-pub fn get_libsearch(logger: &os_access::Os) -> ItwLibSearch {
-    let itw_libs_override = env::var("ITW_LIBS");
-    match itw_libs_override {
-        Ok(result_of_override_var) => match ItwLibSearch::from_str(&result_of_override_var) {
-            Ok(result_of_override_to_enum) => {
-                return result_of_override_to_enum;
+pub mod diagnostics {
+    use annotate_snippets::display_list::{DisplayList, FormatOptions};
+    use annotate_snippets::snippet::{Annotation, AnnotationType, Slice, Snippet, SourceAnnotation};
+    use unicode_width::UnicodeWidthStr;
+
+    pub const ACCEPTED_ITW_LIBS_VALUES: [&str; 3] = ["BUNDLED", "DISTRIBUTION", "BOTH"];
+
+    /// Renders `origin`'s offending `value` with a caret/underline, plus a footer listing the
+    /// accepted alternatives.
+    pub fn invalid_itw_libs_value(origin: &str, value: &str) -> String {
+        let underline_width = UnicodeWidthStr::width(value).max(1);
+        let footer_message = format!("accepted values: {}", ACCEPTED_ITW_LIBS_VALUES.join(", "));
+
+        let snippet = Snippet {
+            title: Some(Annotation {
+                id: None,
+                label: Some("invalid ITW_LIBS value"),
+                annotation_type: AnnotationType::Error,
+            }),
+            footer: vec![Annotation {
+                id: None,
+                label: Some(&footer_message),
+                annotation_type: AnnotationType::Note,
+            }],
+            slices: vec![Slice {
+                source: value,
+                line_start: 1,
+                origin: Some(origin),
+                fold: false,
+                annotations: vec![SourceAnnotation {
+                    range: (0, underline_width),
+                    label: "not one of the accepted values",
+                    annotation_type: AnnotationType::Error,
+                }],
+            }],
+            opt: FormatOptions::default(),
+        };
+
+        DisplayList::from(snippet).to_string()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn renders_offending_value_and_accepted_list() {
+            let rendered = invalid_itw_libs_value("ITW_LIBS", "BOGUS");
+            assert!(rendered.contains("BOGUS"));
+            assert!(rendered.contains("BUNDLED"));
+            assert!(rendered.contains("DISTRIBUTION"));
+            assert!(rendered.contains("BOTH"));
+        }
+    }
+}
+
+/*new variables*/
+
+/// Parses and loads `deployment.properties`-style config files.
+pub mod deployment_config {
+    use std::collections::HashMap;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use os_access;
+
+    /// One optional field per known launcher variable. A missing or unreadable file yields a
+    /// `DeploymentConfig` with every field `None`.
+    #[derive(Debug, Clone, PartialEq, Eq, Default)]
+    pub struct DeploymentConfig {
+        pub jre: Option<String>,
+        pub java: Option<String>,
+        pub main_class: Option<String>,
+        pub name: Option<String>,
+        pub bin: Option<String>,
+        pub splash: Option<String>,
+        pub netx: Option<String>,
+        pub bootcp: Option<String>,
+        pub itw_libs: Option<String>,
+    }
+
+    impl DeploymentConfig {
+        /// Parses `KEY=VALUE` lines, skipping blank lines and `#` comments. A malformed line
+        /// is reported through `logger` and skipped, not fatal.
+        pub fn parse(contents: &str, logger: &os_access::Os) -> DeploymentConfig {
+            let mut values: HashMap<String, String> = HashMap::new();
+            for (line_number, raw_line) in contents.lines().enumerate() {
+                let line = raw_line.trim();
+                if line.is_empty() || line.starts_with('#') {
+                    continue;
+                }
+                match line.find('=') {
+                    Some(index) => {
+                        let key = line[..index].trim().to_string();
+                        let value = line[index + 1..].trim().to_string();
+                        values.insert(key, value);
+                    }
+                    None => {
+                        logger.info(&format!(
+                            "deployment config line {} is malformed, expected KEY=VALUE: {}",
+                            line_number + 1,
+                            raw_line
+                        ));
+                    }
+                }
             }
-            _Err => {
-                let mut info = String::new();
-                write!(&mut info, "ITW-LIBS provided, but have invalid value of {}. Use BUNDLED, DISTRIBUTION or BOTH", result_of_override_var);
-                logger.info(&info);
+
+            DeploymentConfig {
+                jre: values.remove("JRE"),
+                java: values.remove("JAVA"),
+                main_class: values.remove("MAIN_CLASS"),
+                name: values.remove("PROGRAM_NAME"),
+                bin: values.remove("BIN_LOCATION"),
+                splash: values.remove("SPLASH_PNG"),
+                netx: values.remove("NETX_JAR"),
+                bootcp: values.remove("LAUNCHER_BOOTCLASSPATH"),
+                itw_libs: values.remove("ITW_LIBS"),
             }
         }
-        _error => {
-            //no op, continuing via get_itwlibsearch
+
+        pub fn load(path: &Path, logger: &os_access::Os) -> DeploymentConfig {
+            match fs::read_to_string(path) {
+                Ok(contents) => DeploymentConfig::parse(&contents, logger),
+                Err(_) => DeploymentConfig::default(),
+            }
         }
     }
-    match ItwLibSearch::from_str(get_itwlibsearch()) {
-        Ok(v) => {
-            return v
+
+    #[cfg(not(windows))]
+    pub fn default_path() -> PathBuf {
+        let home = ::std::env::var("HOME").unwrap_or_else(|_| String::from("."));
+        Path::new(&home).join(".config").join("icedtea-web").join("deployment.properties")
+    }
+
+    /// `HOME` is typically unset on Windows, so this uses `APPDATA` (falling back to
+    /// `USERPROFILE`) instead.
+    #[cfg(windows)]
+    pub fn default_path() -> PathBuf {
+        let app_data = ::std::env::var("APPDATA")
+            .or_else(|_| ::std::env::var("USERPROFILE"))
+            .unwrap_or_else(|_| String::from("."));
+        Path::new(&app_data).join("icedtea-web").join("deployment.properties")
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn missing_file_yields_empty_config() {
+            let logger = os_access::Os::new();
+            let config = DeploymentConfig::load(Path::new("/nonexistent/deployment.properties"), &logger);
+            assert_eq!(config, DeploymentConfig::default());
         }
-        _Err=> {
-            panic!("itw-lib search out of range");
+
+        #[test]
+        fn malformed_line_is_skipped_not_fatal() {
+            let logger = os_access::Os::new();
+            let config = DeploymentConfig::parse("this-line-has-no-equals\nJRE=/opt/jre", &logger);
+            assert_eq!(config.jre, Some(String::from("/opt/jre")));
+        }
+
+        #[test]
+        fn comments_and_blank_lines_are_ignored() {
+            let logger = os_access::Os::new();
+            let config = DeploymentConfig::parse("# a comment\n\nJAVA=/opt/jre/bin/java\n", &logger);
+            assert_eq!(config.java, Some(String::from("/opt/jre/bin/java")));
+        }
+
+        #[test]
+        fn known_keys_are_parsed_into_their_fields() {
+            let logger = os_access::Os::new();
+            let config = DeploymentConfig::parse("ITW_LIBS=BOTH\nMAIN_CLASS=net.sourceforge.jnlp.runtime.Boot", &logger);
+            assert_eq!(config.itw_libs, Some(String::from("BOTH")));
+            assert_eq!(config.main_class, Some(String::from("net.sourceforge.jnlp.runtime.Boot")));
         }
     }
 }
 
+pub mod args {
+    use getopts::Options;
+    use os_access;
+    use std::str::FromStr;
+    use super::ItwLibSearch;
 
-/*new variables*/
+    #[derive(Debug, Clone, PartialEq, Eq, Default)]
+    pub struct CliOverrides {
+        pub jre: Option<String>,
+        pub java: Option<String>,
+        pub main_class: Option<String>,
+        pub itw_libs: Option<String>,
+    }
+
+    fn options() -> Options {
+        let mut opts = Options::new();
+        opts.optopt("", "jre", "override the JRE path", "PATH");
+        opts.optopt("", "java", "override the java executable path", "PATH");
+        opts.optopt("", "main", "override the main class", "CLASS");
+        opts.optopt("", "itw-libs", "override the itw lib search mode", "BUNDLED|DISTRIBUTION|BOTH");
+        opts
+    }
+
+    /// An unknown flag, or an out-of-range `--itw-libs` value, is reported through `logger`
+    /// rather than aborting the launcher.
+    pub fn parse(program: &str, args: &[String], logger: &os_access::Os) -> CliOverrides {
+        let opts = options();
+        let matches = match opts.parse(args) {
+            Ok(matches) => matches,
+            Err(failure) => {
+                logger.info(&format!("{}\n{}", failure, opts.usage(&format!("Usage: {} [options]", program))));
+                return CliOverrides::default();
+            }
+        };
+
+        let itw_libs = matches.opt_str("itw-libs").and_then(|value| match ItwLibSearch::from_str(&value) {
+            Ok(_) => Some(value),
+            Err(_) => {
+                logger.info(&super::diagnostics::invalid_itw_libs_value("--itw-libs", &value));
+                None
+            }
+        });
+
+        CliOverrides {
+            jre: matches.opt_str("jre"),
+            java: matches.opt_str("java"),
+            main_class: matches.opt_str("main"),
+            itw_libs,
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn unrecognized_flags_yield_default_overrides() {
+            let logger = os_access::Os::new();
+            let overrides = parse("itw-launcher", &[String::from("--bogus-flag")], &logger);
+            assert_eq!(overrides, CliOverrides::default());
+        }
+
+        #[test]
+        fn jre_and_main_flags_are_captured() {
+            let logger = os_access::Os::new();
+            let raw = vec![
+                String::from("--jre"), String::from("/opt/custom-jre"),
+                String::from("--main"), String::from("net.sourceforge.jnlp.runtime.Boot"),
+            ];
+            let overrides = parse("itw-launcher", &raw, &logger);
+            assert_eq!(overrides.jre, Some(String::from("/opt/custom-jre")));
+            assert_eq!(overrides.main_class, Some(String::from("net.sourceforge.jnlp.runtime.Boot")));
+        }
+
+        #[test]
+        fn valid_itw_libs_flag_is_captured() {
+            let logger = os_access::Os::new();
+            let raw = vec![String::from("--itw-libs"), String::from("BOTH")];
+            let overrides = parse("itw-launcher", &raw, &logger);
+            assert_eq!(overrides.itw_libs, Some(String::from("BOTH")));
+        }
+
+        #[test]
+        fn out_of_range_itw_libs_flag_is_rejected_not_fatal() {
+            let logger = os_access::Os::new();
+            let raw = vec![String::from("--itw-libs"), String::from("NOT_A_REAL_MODE")];
+            let overrides = parse("itw-launcher", &raw, &logger);
+            assert_eq!(overrides.itw_libs, None);
+        }
+    }
+}
+
+/// Precedence, highest first: command-line flag, env var, deployment config entry, build-time
+/// default. Any override taken is logged through `logger`.
+fn resolve(var_name: &str, cli_value: Option<&str>, config_value: Option<&str>, build_default: &str, logger: &os_access::Os) -> String {
+    if let Some(value) = cli_value {
+        let mut info = String::new();
+        write!(&mut info, "{} overridden via command-line flag to {}", var_name, value).unwrap();
+        logger.info(&info);
+        return String::from(value);
+    }
+    match env::var(var_name) {
+        Ok(value) => {
+            let mut info = String::new();
+            write!(&mut info, "{} overridden at runtime to {}", var_name, value).unwrap();
+            logger.info(&info);
+            value
+        }
+        Err(_) => match config_value {
+            Some(value) => {
+                logger.info(&format!("{} overridden via deployment config to {}", var_name, value));
+                String::from(value)
+            }
+            None => String::from(build_default),
+        },
+    }
+}
+
+/// Like `resolve`, but each candidate is validated via `ItwLibSearch::from_str`; an invalid
+/// value is reported through `diagnostics::invalid_itw_libs_value` and treated as absent
+/// rather than passed through.
+fn resolve_itw_libs(cli_value: Option<&str>, config_value: Option<&str>, build_default: &str, logger: &os_access::Os) -> String {
+    if let Some(value) = cli_value {
+        if ItwLibSearch::from_str(value).is_ok() {
+            logger.info(&format!("ITW_LIBS overridden via command-line flag to {}", value));
+            return String::from(value);
+        }
+        logger.info(&diagnostics::invalid_itw_libs_value("ITW_LIBS (command line)", value));
+    }
+    if let Ok(value) = env::var("ITW_LIBS") {
+        if ItwLibSearch::from_str(&value).is_ok() {
+            logger.info(&format!("ITW_LIBS overridden at runtime to {}", value));
+            return value;
+        }
+        logger.info(&diagnostics::invalid_itw_libs_value("ITW_LIBS (env)", &value));
+    }
+    if let Some(value) = config_value {
+        if ItwLibSearch::from_str(value).is_ok() {
+            logger.info(&format!("ITW_LIBS overridden via deployment config to {}", value));
+            return String::from(value);
+        }
+        logger.info(&diagnostics::invalid_itw_libs_value("ITW_LIBS (config file)", value));
+    }
+    if ItwLibSearch::from_str(build_default).is_ok() {
+        String::from(build_default)
+    } else {
+        logger.info(&diagnostics::invalid_itw_libs_value("ITW_LIBS (build-time)", build_default));
+        String::from("BOTH")
+    }
+}
+
+/// The fully resolved launcher configuration, built once so it can be asserted in tests
+/// instead of reading globals from a pile of free functions.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Config {
+    pub jre: String,
+    pub java: String,
+    pub main_class: String,
+    pub name: String,
+    pub bin: String,
+    pub splash: String,
+    pub netx: String,
+    pub bootcp: String,
+    pub itw_libs: String,
+}
+
+impl Config {
+    pub fn resolve(logger: &os_access::Os) -> Config {
+        let raw_args: Vec<String> = env::args().collect();
+        let program = raw_args.get(0).cloned().unwrap_or_else(|| String::from("itw-launcher"));
+        let cli = args::parse(&program, &raw_args[1..], logger);
+        let deployment = deployment_config::DeploymentConfig::load(&deployment_config::default_path(), logger);
+        Config::resolve_with_overrides(&cli, &deployment, logger)
+    }
+
+    pub fn resolve_with_overrides(cli: &args::CliOverrides, deployment: &deployment_config::DeploymentConfig, logger: &os_access::Os) -> Config {
+        Config {
+            jre: resolve("JRE", cli.jre.as_deref(), deployment.jre.as_deref(), &get_jre(logger), logger),
+            java: resolve("JAVA", cli.java.as_deref(), deployment.java.as_deref(), &get_java(logger), logger),
+            main_class: resolve("MAIN_CLASS", cli.main_class.as_deref(), deployment.main_class.as_deref(), get_main(), logger),
+            name: resolve("PROGRAM_NAME", None, deployment.name.as_deref(), get_name(), logger),
+            bin: resolve("BIN_LOCATION", None, deployment.bin.as_deref(), get_bin(), logger),
+            splash: resolve("SPLASH_PNG", None, deployment.splash.as_deref(), get_splash(), logger),
+            netx: resolve("NETX_JAR", None, deployment.netx.as_deref(), get_netx(), logger),
+            bootcp: resolve("LAUNCHER_BOOTCLASSPATH", None, deployment.bootcp.as_deref(), get_bootcp(), logger),
+            itw_libs: resolve_itw_libs(cli.itw_libs.as_deref(), deployment.itw_libs.as_deref(), get_itwlibsearch(), logger),
+        }
+    }
+}
 
 /*tests*/
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
+    use std::env;
 
     #[test]
     fn variables_non_default() {
-        assert_ne!(String::from(super::get_jre()).trim(), String::from("JRE-dev-unspecified"));
-        assert_ne!(String::from(super::get_java()).trim(), String::from("JAVA-dev-unspecified"));
+        let logger = os_access::Os::new();
+        assert_ne!(super::get_jre(&logger).trim(), String::from("JRE-dev-unspecified"));
+        assert_ne!(super::get_java(&logger).trim(), String::from("JAVA-dev-unspecified"));
         assert_ne!(String::from(super::get_main()).trim(), String::from("MAIN_CLASS-dev-unspecified"));
         assert_ne!(String::from(super::get_name()).trim(), String::from("PROGRAM_NAME-dev-unspecified"));
         assert_ne!(String::from(super::get_bin()).trim(), String::from("BIN_LOCATION-dev-unspecified"));
@@ -141,8 +728,9 @@ mod tests {
 
     #[test]
     fn variables_non_empty() {
-        assert_ne!(String::from(super::get_jre()).trim(), String::from(""));
-        assert_ne!(String::from(super::get_java()).trim(), String::from(""));
+        let logger = os_access::Os::new();
+        assert_ne!(super::get_jre(&logger).trim(), String::from(""));
+        assert_ne!(super::get_java(&logger).trim(), String::from(""));
         assert_ne!(String::from(super::get_main()).trim(), String::from(""));
         assert_ne!(String::from(super::get_name()).trim(), String::from(""));
         assert_ne!(String::from(super::get_bin()).trim(), String::from(""));
@@ -163,4 +751,88 @@ mod tests {
         assert!(super::ItwLibSearch::from_str("DISTRIBUTION") == Ok(super::ItwLibSearch::DISTRIBUTION));
         assert!(super::ItwLibSearch::from_str("") == Err(super::ParseItwLibSearch { _priv: () }));
     }
+
+    #[test]
+    fn resolve_falls_back_to_build_default_when_nothing_else_set() {
+        env::remove_var("ITW_HARDCODED_PATHS_TEST_UNSET");
+        let logger = os_access::Os::new();
+        let resolved = super::resolve("ITW_HARDCODED_PATHS_TEST_UNSET", None, None, "the-default", &logger);
+        assert_eq!(resolved, String::from("the-default"));
+    }
+
+    #[test]
+    fn resolve_prefers_config_file_value_over_build_default() {
+        env::remove_var("ITW_HARDCODED_PATHS_TEST_CONFIG");
+        let logger = os_access::Os::new();
+        let resolved = super::resolve("ITW_HARDCODED_PATHS_TEST_CONFIG", None, Some("from-config-file"), "the-default", &logger);
+        assert_eq!(resolved, String::from("from-config-file"));
+    }
+
+    #[test]
+    fn resolve_prefers_env_override_over_config_file_and_build_default() {
+        env::set_var("ITW_HARDCODED_PATHS_TEST_SET", "overridden-value");
+        let logger = os_access::Os::new();
+        let resolved = super::resolve("ITW_HARDCODED_PATHS_TEST_SET", None, Some("from-config-file"), "the-default", &logger);
+        assert_eq!(resolved, String::from("overridden-value"));
+        env::remove_var("ITW_HARDCODED_PATHS_TEST_SET");
+    }
+
+    #[test]
+    fn resolve_prefers_cli_flag_over_env_config_and_build_default() {
+        env::set_var("ITW_HARDCODED_PATHS_TEST_CLI", "from-env");
+        let logger = os_access::Os::new();
+        let resolved = super::resolve("ITW_HARDCODED_PATHS_TEST_CLI", Some("from-cli"), Some("from-config-file"), "the-default", &logger);
+        assert_eq!(resolved, String::from("from-cli"));
+        env::remove_var("ITW_HARDCODED_PATHS_TEST_CLI");
+    }
+
+    #[test]
+    fn config_resolve_matches_build_defaults_when_no_overrides_set() {
+        let logger = os_access::Os::new();
+        let config = super::Config::resolve(&logger);
+        assert_eq!(config.jre, super::get_jre(&logger));
+        assert_eq!(config.java, super::get_java(&logger));
+        assert_eq!(config.main_class, String::from(super::get_main()));
+        assert_eq!(config.itw_libs, String::from(super::get_itwlibsearch()));
+    }
+
+    #[test]
+    fn config_resolve_with_overrides_prefers_deployment_over_build_default() {
+        let logger = os_access::Os::new();
+        let cli = super::args::CliOverrides::default();
+        let deployment = super::deployment_config::DeploymentConfig {
+            jre: Some(String::from("/opt/deployment-jre")),
+            ..Default::default()
+        };
+        let config = super::Config::resolve_with_overrides(&cli, &deployment, &logger);
+        assert_eq!(config.jre, String::from("/opt/deployment-jre"));
+    }
+
+    #[test]
+    fn config_resolve_with_overrides_prefers_cli_over_deployment() {
+        let logger = os_access::Os::new();
+        let cli = super::args::CliOverrides {
+            jre: Some(String::from("/opt/cli-jre")),
+            ..Default::default()
+        };
+        let deployment = super::deployment_config::DeploymentConfig {
+            jre: Some(String::from("/opt/deployment-jre")),
+            ..Default::default()
+        };
+        let config = super::Config::resolve_with_overrides(&cli, &deployment, &logger);
+        assert_eq!(config.jre, String::from("/opt/cli-jre"));
+    }
+
+    #[test]
+    fn config_resolve_with_overrides_rejects_invalid_deployment_itw_libs() {
+        env::remove_var("ITW_LIBS");
+        let logger = os_access::Os::new();
+        let cli = super::args::CliOverrides::default();
+        let deployment = super::deployment_config::DeploymentConfig {
+            itw_libs: Some(String::from("BOGUS")),
+            ..Default::default()
+        };
+        let config = super::Config::resolve_with_overrides(&cli, &deployment, &logger);
+        assert_eq!(config.itw_libs, String::from(super::get_itwlibsearch()));
+    }
 }
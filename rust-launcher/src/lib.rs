@@ -0,0 +1 @@
+pub mod hardcoded_paths;